@@ -10,3 +10,6 @@ gcc --version
 # Build and test
 cargo build --verbose
 cargo test --verbose
+
+# Run the binding smoke test without hugepages or devices
+cargo run --verbose --bin dpdk_sys_test -- --no-huge --no-pci --in-memory
@@ -22,5 +22,61 @@ fn main() {
 
         assert_eq!(dpdk_sys::rte_is_power_of_2(7), 0);
         assert_eq!(dpdk_sys::rte_is_power_of_2(16), 1);
+
+        let linked = |pmd: &str| dpdk_sys::build_info::LINKED_PMDS.contains(&pmd);
+        let empty = ffi::CString::new("").unwrap();
+
+        // Software eventdev must be registered without hardware.
+        if linked("rte_event_sw") {
+            let event_sw = ffi::CString::new("event_sw0").unwrap();
+            assert_eq!(
+                dpdk_sys::rte_vdev_init(event_sw.as_ptr(), empty.as_ptr()),
+                0
+            );
+            assert!(dpdk_sys::rte_event_dev_get_dev_id(event_sw.as_ptr()) >= 0);
+        }
+
+        // Software compressdev is only linked when libz is available.
+        if linked("rte_compress_zlib") {
+            let compress_zlib = ffi::CString::new("compress_zlib0").unwrap();
+            assert_eq!(
+                dpdk_sys::rte_vdev_init(compress_zlib.as_ptr(), empty.as_ptr()),
+                0
+            );
+        }
+
+        // Mempool drivers other than the built-in ring ops must be selectable.
+        for (pmd, ops) in [
+            ("rte_mempool_stack", "stack"),
+            ("rte_mempool_bucket", "bucket"),
+        ] {
+            if !linked(pmd) {
+                continue;
+            }
+            let pool_name = ffi::CString::new(format!("test_{}", ops)).unwrap();
+            let ops_name = ffi::CString::new(ops).unwrap();
+            let mp = dpdk_sys::rte_mempool_create_empty(
+                pool_name.as_ptr(),
+                1023,
+                64,
+                0,
+                0,
+                dpdk_sys::rte_socket_id() as c_int,
+                0,
+            );
+            assert!(!mp.is_null());
+            assert_eq!(
+                dpdk_sys::rte_mempool_set_ops_byname(mp, ops_name.as_ptr(), std::ptr::null_mut()),
+                0
+            );
+            dpdk_sys::rte_mempool_free(mp);
+        }
+
+        println!(
+            "{:?} ({:#018x})",
+            ffi::CStr::from_ptr(dpdk_sys::rte_version()),
+            dpdk_sys::build_info::DPDK_HEADER_HASH
+        );
+        println!("{:?}", dpdk_sys::build_info::LINKED_PMDS);
     }
 }
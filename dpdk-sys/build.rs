@@ -38,6 +38,17 @@ fn strip_comments(comment: String) -> String {
         .join("\n")
 }
 
+/// Feed `bytes` into a FNV-1a (64-bit) hash.
+///
+/// Unlike `DefaultHasher`, the result is stable across Rust releases.
+fn fnv1a_64(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
 /// Information needed to generate DPDK binding.
 ///
 /// Each information is filled at different build stages.
@@ -78,6 +89,9 @@ struct State {
 
     /// Macro constants are not expanded when it uses other macro functions.
     static_constants: String,
+
+    /// PMDs linked as whole archives, coupled with their external dependencies.
+    pmd_links: Vec<(&'static str, Vec<&'static str>)>,
 }
 
 impl State {
@@ -99,6 +113,7 @@ impl State {
             global_eal_function_use_defs: Default::default(),
             static_functions: Default::default(),
             static_constants: Default::default(),
+            pmd_links: Default::default(),
         }
     }
 
@@ -306,6 +321,26 @@ impl State {
 
         // Remove blacklist headers
         let blacklist_prefix = vec!["rte_acc_"];
+
+        // Sub-headers are usually included by their parent header (e.g. `rte_mbuf_core.h` by
+        // `rte_mbuf.h`) and skipped below. Following ones are standalone APIs, keep them.
+        let sub_header_whitelist = vec![
+            "rte_bpf_ethdev",
+            "rte_bus_vdev",
+            "rte_eth_bond_8023ad",
+            "rte_graph_worker",
+            "rte_port_ethdev",
+            "rte_port_ring",
+            "rte_port_source_sink",
+            "rte_power_pmd_mgmt",
+            "rte_swx_port_ethdev",
+            "rte_swx_port_ring",
+            "rte_swx_port_source_sink",
+            "rte_table_array",
+            "rte_table_hash",
+            "rte_table_lpm",
+            "rte_table_stub",
+        ];
         let mut name_set: Vec<String> = vec![];
         for file in &headers {
             let file_name = String::from(file.file_stem().unwrap().to_str().unwrap());
@@ -314,9 +349,11 @@ impl State {
         let mut new_vec = vec![];
         'outer: for file in &headers {
             let file_name = file.file_stem().unwrap().to_str().unwrap();
-            for prev_name in &name_set {
-                if file_name.starts_with(&format!("{}_", prev_name)) {
-                    continue 'outer;
+            if !sub_header_whitelist.contains(&file_name) {
+                for prev_name in &name_set {
+                    if file_name.starts_with(&format!("{}_", prev_name)) {
+                        continue 'outer;
+                    }
                 }
             }
             for platform in &platform_set {
@@ -899,37 +936,68 @@ impl State {
                 .join("\n"),
         );
 
+        // Record how this binding was generated (see `build_info` in `lib.rs.template`).
+        // All installed `rte_*.h` headers are hashed, including sub-headers (e.g.
+        // `rte_mbuf_core.h`) and `generic/`, which are not listed in `dpdk_headers`. Other
+        // headers may share the include directory and are left out.
+        let include_dir = self.include_path.as_ref().unwrap();
+        let mut header_files = vec![];
+        for dir in [include_dir.clone(), include_dir.join("generic")] {
+            for entry in dir.read_dir().into_iter().flatten().flatten() {
+                let path = entry.path();
+                let is_rte_header = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("rte_") && name.ends_with(".h"));
+                if path.is_file() && is_rte_header {
+                    header_files.push(path);
+                }
+            }
+        }
+        header_files.sort();
+        let mut header_hash = 0xcbf29ce484222325;
+        for path in &header_files {
+            if let Ok(content) = fs::read(path) {
+                let name = path.strip_prefix(include_dir).unwrap();
+                header_hash = fnv1a_64(header_hash, name.to_str().unwrap().as_bytes());
+                header_hash = fnv1a_64(header_hash, &content);
+            }
+        }
+        let formatted_string = formatted_string
+            .replace(
+                "%dpdk_headers%",
+                &self
+                    .dpdk_headers
+                    .iter()
+                    .map(|header| format!("{:?}", header))
+                    .join(", "),
+            )
+            .replace("%dpdk_header_hash%", &format!("{:#018x}", header_hash))
+            .replace(
+                "%linked_pmds%",
+                &self
+                    .pmd_links
+                    .iter()
+                    .map(|(name, _)| format!("{:?}", name))
+                    .join(", "),
+            )
+            .replace(
+                "%constants_cached%",
+                &env::var("CARGO_FEATURE_CONSTANTS_CACHE")
+                    .is_ok()
+                    .to_string(),
+            );
+
         let mut target = File::create(target_path).unwrap();
         target.write_fmt(format_args!("{}", formatted_string)).ok();
     }
 
-    /// Do compile.
-    fn compile(&mut self) {
-        let dpdk_include_path = self.include_path.as_ref().unwrap();
-        let dpdk_config = self.dpdk_config.as_ref().unwrap();
-        let source_path = self.out_path.join("static.c");
-        let lib_path = self.library_path.as_ref().unwrap();
-
-        cc::Build::new()
-            .file(source_path)
-            .static_flag(true)
-            .shared_flag(false)
-            .opt_level(3)
-            .include(dpdk_include_path)
-            .include(&self.out_path)
-            .flag("-w") // hide warnings
-            .flag("-march=native")
-            .flag("-imacros")
-            .flag(dpdk_config.to_str().unwrap())
-            // .flag(&format!("-L{}", lib_path.to_str().unwrap()))
-            // .flag("-ldpdk")
-            .compile("lib_static_wrapper.a");
-
-        println!(
-            "cargo:rustc-link-search=native={}",
-            lib_path.to_str().unwrap()
-        );
-
+    /// Select PMDs to be linked as whole archives.
+    ///
+    /// PMDs register themselves from constructors, so they are dropped by the linker unless
+    /// explicitly linked. Candidates which are not built in this DPDK installation or whose
+    /// external dependencies are missing are skipped.
+    fn find_linkable_pmds(&mut self) {
         let pmd_whitelist_candidate = vec![
             ("rte_net_af_packet", vec![]),
             ("rte_net_af_xdp", vec!["xdp", "bpf"]),
@@ -975,15 +1043,27 @@ impl State {
             ("rte_net_vhost", vec![]),
             ("rte_net_virtio", vec![]),
             ("rte_net_vmxnet3", vec![]),
+            ("rte_event_sw", vec![]),
+            ("rte_compress_zlib", vec!["z"]),
+            ("rte_mempool_bucket", vec![]),
+            ("rte_mempool_stack", vec![]),
         ];
-        let mut pmd_whitelist = Vec::new();
 
         let test_template = self.project_path.join("gen/link_test.c");
         let builder = cc::Build::new();
         let compiler = builder.get_compiler();
         let cc_name = compiler.path().to_str().unwrap().to_string();
 
+        let available_libs: Vec<_> = self
+            .dpdk_links
+            .iter()
+            .map(|link| link.file_stem().unwrap().to_str().unwrap().to_string())
+            .collect();
+
         for (name, deps) in pmd_whitelist_candidate.into_iter() {
+            if !available_libs.contains(&format!("lib{}", name)) {
+                continue;
+            }
             let mut skip_due_to = Vec::new();
             for dep in &deps {
                 let ret = Command::new(cc_name.clone())
@@ -1005,8 +1085,37 @@ impl State {
                 );
                 continue;
             }
-            pmd_whitelist.push((name, deps));
+            self.pmd_links.push((name, deps));
         }
+    }
+
+    /// Do compile.
+    fn compile(&mut self) {
+        let dpdk_include_path = self.include_path.as_ref().unwrap();
+        let dpdk_config = self.dpdk_config.as_ref().unwrap();
+        let source_path = self.out_path.join("static.c");
+        let lib_path = self.library_path.as_ref().unwrap();
+
+        cc::Build::new()
+            .file(source_path)
+            .static_flag(true)
+            .shared_flag(false)
+            .opt_level(3)
+            .include(dpdk_include_path)
+            .include(&self.out_path)
+            .flag("-w") // hide warnings
+            .flag("-march=native")
+            .flag("-imacros")
+            .flag(dpdk_config.to_str().unwrap())
+            // .flag(&format!("-L{}", lib_path.to_str().unwrap()))
+            // .flag("-ldpdk")
+            .compile("lib_static_wrapper.a");
+
+        println!(
+            "cargo:rustc-link-search=native={}",
+            lib_path.to_str().unwrap()
+        );
+
         let mut rte_libs: Vec<_> = Vec::new();
         let mut additional_libs: Vec<&'static str> = vec![];
 
@@ -1020,7 +1129,7 @@ impl State {
                 if link_name == "dpdk" {
                     continue;
                 }
-                for (name, deps) in pmd_whitelist.iter() {
+                for (name, deps) in self.pmd_links.iter() {
                     if *name == link_name {
                         additional_libs.extend(deps.iter());
                         println!(
@@ -1061,6 +1170,7 @@ fn main() {
     state.extract_eal_apis();
     state.generate_static_impls_and_link_pmds();
     state.generate_rust_def();
+    state.find_linkable_pmds();
     state.generate_lib_rs();
     state.compile();
 }
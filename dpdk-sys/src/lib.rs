@@ -10,4 +10,6 @@
 mod dpdk;
 pub use dpdk::*;
 
+pub mod mbuf;
+
 include!(concat!(env!("OUT_DIR"), "/lib.rs"));
@@ -0,0 +1,72 @@
+//! Typed helpers for mbuf sizing macros.
+//!
+//! `constants` exposes the raw macro values as `u32`/`u64`. The helpers here convert them into
+//! the `u16` used by `rte_pktmbuf_pool_create` and derive a `data_room_size` for a given MTU.
+
+use crate::constants;
+
+/// Headroom reserved in front of packet data (`RTE_PKTMBUF_HEADROOM`).
+#[inline(always)]
+pub const fn pktmbuf_headroom() -> u16 {
+    constants::RTE_PKTMBUF_HEADROOM as u16
+}
+
+/// Default data room of an mbuf, excluding headroom (`RTE_MBUF_DEFAULT_DATAROOM`).
+#[inline(always)]
+pub const fn mbuf_default_dataroom() -> u16 {
+    constants::RTE_MBUF_DEFAULT_DATAROOM as u16
+}
+
+/// Default `data_room_size` of a packet mempool, i.e. data room plus headroom
+/// (`RTE_MBUF_DEFAULT_BUF_SIZE`).
+#[inline(always)]
+pub const fn mbuf_default_buf_size() -> u16 {
+    constants::RTE_MBUF_DEFAULT_BUF_SIZE as u16
+}
+
+/// Largest frame carrying `mtu` bytes of L3 payload: Ethernet header, two VLAN tags and CRC.
+#[inline(always)]
+pub const fn frame_len(mtu: u32) -> u32 {
+    mtu.saturating_add(
+        constants::RTE_ETHER_HDR_LEN + 2 * constants::RTE_VLAN_HLEN + constants::RTE_ETHER_CRC_LEN,
+    )
+}
+
+/// `data_room_size` for `rte_pktmbuf_pool_create` so that a frame of the given MTU fits into a
+/// single mbuf.
+///
+/// Never smaller than `RTE_MBUF_DEFAULT_BUF_SIZE`. Returns `None` if the required size does not
+/// fit into `u16`; such frames must be received as multi-segment mbufs instead.
+#[inline(always)]
+pub const fn pktmbuf_data_room_size(mtu: u32) -> Option<u16> {
+    let required = frame_len(mtu) as u64 + constants::RTE_PKTMBUF_HEADROOM as u64;
+    if required > u16::MAX as u64 {
+        None
+    } else if required < constants::RTE_MBUF_DEFAULT_BUF_SIZE as u64 {
+        Some(mbuf_default_buf_size())
+    } else {
+        Some(required as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_room_size() {
+        assert_eq!(
+            pktmbuf_data_room_size(constants::RTE_ETHER_MTU),
+            Some(mbuf_default_buf_size())
+        );
+        assert_eq!(
+            pktmbuf_data_room_size(9000),
+            Some((frame_len(9000) + constants::RTE_PKTMBUF_HEADROOM) as u16)
+        );
+
+        let max_mtu = u16::MAX as u32 - constants::RTE_PKTMBUF_HEADROOM - frame_len(0);
+        assert_eq!(pktmbuf_data_room_size(max_mtu), Some(u16::MAX));
+        assert_eq!(pktmbuf_data_room_size(max_mtu + 1), None);
+        assert_eq!(pktmbuf_data_room_size(u32::MAX), None);
+    }
+}